@@ -0,0 +1,94 @@
+// programs/chess-host-util/src/lib.rs
+//
+// FEN parsing shared by every `script` host crate in the workspace, so the
+// board/castling/en-passant decoding only needs to be correct in one place
+// instead of being copy-pasted into each prover script.
+
+// Parses the board, side-to-move, castling rights and en-passant file out
+// of a FEN string. Pieces are signed: positive is white, negative is
+// black, magnitude is pawn=1, knight=2, bishop=3, rook=4, queen=5, king=6.
+// Castling rights are packed as a bitmask: 1=K, 2=Q, 4=k, 8=q. The
+// en-passant file is 0-7 (a-h), or 8 if there is none.
+pub fn parse_fen(fen: &str) -> ([i8; 64], u8, u8, u8) {
+    let mut fields = fen.split_whitespace();
+    let board_field = fields.next().unwrap_or("8/8/8/8/8/8/8/8");
+    let active_color = fields.next().unwrap_or("w");
+    let castling_field = fields.next().unwrap_or("-");
+    let en_passant_field = fields.next().unwrap_or("-");
+
+    let mut board = [0i8; 64];
+    for (rank_from_top, rank_str) in board_field.split('/').enumerate() {
+        let rank = 7 - rank_from_top;
+        let mut file = 0usize;
+        for ch in rank_str.chars() {
+            if let Some(empty_squares) = ch.to_digit(10) {
+                file += empty_squares as usize;
+            } else {
+                board[rank * 8 + file] = piece_from_fen_char(ch);
+                file += 1;
+            }
+        }
+    }
+
+    let player_turn = if active_color == "b" { 1 } else { 0 };
+
+    let mut castling_rights = 0u8;
+    if castling_field.contains('K') {
+        castling_rights |= 1;
+    }
+    if castling_field.contains('Q') {
+        castling_rights |= 2;
+    }
+    if castling_field.contains('k') {
+        castling_rights |= 4;
+    }
+    if castling_field.contains('q') {
+        castling_rights |= 8;
+    }
+
+    let en_passant_file = if en_passant_field == "-" {
+        8
+    } else {
+        en_passant_field.as_bytes()[0] - b'a'
+    };
+
+    (board, player_turn, castling_rights, en_passant_file)
+}
+
+fn piece_from_fen_char(ch: char) -> i8 {
+    let magnitude: i8 = match ch.to_ascii_uppercase() {
+        'P' => 1,
+        'N' => 2,
+        'B' => 3,
+        'R' => 4,
+        'Q' => 5,
+        'K' => 6,
+        _ => 0,
+    };
+    if ch.is_ascii_uppercase() { magnitude } else { -magnitude }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_starting_position() {
+        let (board, player_turn, castling_rights, en_passant_file) =
+            parse_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+        assert_eq!(board[0], 4); // white rook a1
+        assert_eq!(board[4], 6); // white king e1
+        assert_eq!(board[60], -6); // black king e8
+        assert_eq!(player_turn, 0);
+        assert_eq!(castling_rights, 0b1111);
+        assert_eq!(en_passant_file, 8);
+    }
+
+    #[test]
+    fn test_parse_en_passant_file() {
+        let (_, player_turn, _, en_passant_file) =
+            parse_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1");
+        assert_eq!(player_turn, 1);
+        assert_eq!(en_passant_file, 4);
+    }
+}
@@ -0,0 +1,108 @@
+// Precomputes knight/king/pawn attack bitboards and per-direction sliding
+// ray masks, indexed by square, so the guest can look them up instead of
+// walking the board array at proving time.
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+const KNIGHT_DELTAS: [(i32, i32); 8] = [
+    (1, 2), (2, 1), (2, -1), (1, -2),
+    (-1, -2), (-2, -1), (-2, 1), (-1, 2),
+];
+
+const KING_DELTAS: [(i32, i32); 8] = [
+    (1, 0), (1, 1), (0, 1), (-1, 1),
+    (-1, 0), (-1, -1), (0, -1), (1, -1),
+];
+
+// N, NE, E, SE, S, SW, W, NW
+const RAY_DELTAS: [(i32, i32); 8] = [
+    (0, 1), (1, 1), (1, 0), (1, -1),
+    (0, -1), (-1, -1), (-1, 0), (-1, 1),
+];
+
+fn on_board(file: i32, rank: i32) -> bool {
+    (0..8).contains(&file) && (0..8).contains(&rank)
+}
+
+fn main() {
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let dest_path = Path::new(&out_dir).join("generated.rs");
+
+    let mut knight_attacks = [0u64; 64];
+    let mut king_attacks = [0u64; 64];
+    let mut pawn_attacks_white = [0u64; 64];
+    let mut pawn_attacks_black = [0u64; 64];
+    let mut ray_masks = [[0u64; 64]; 8];
+
+    for square in 0..64i32 {
+        let (file, rank) = (square % 8, square / 8);
+
+        for &(df, dr) in KNIGHT_DELTAS.iter() {
+            let (tf, tr) = (file + df, rank + dr);
+            if on_board(tf, tr) {
+                knight_attacks[square as usize] |= 1u64 << (tr * 8 + tf);
+            }
+        }
+
+        for &(df, dr) in KING_DELTAS.iter() {
+            let (tf, tr) = (file + df, rank + dr);
+            if on_board(tf, tr) {
+                king_attacks[square as usize] |= 1u64 << (tr * 8 + tf);
+            }
+        }
+
+        for &(df, dr) in [(-1, 1), (1, 1)].iter() {
+            let (tf, tr) = (file + df, rank + dr);
+            if on_board(tf, tr) {
+                pawn_attacks_white[square as usize] |= 1u64 << (tr * 8 + tf);
+            }
+        }
+
+        for &(df, dr) in [(-1, -1), (1, -1)].iter() {
+            let (tf, tr) = (file + df, rank + dr);
+            if on_board(tf, tr) {
+                pawn_attacks_black[square as usize] |= 1u64 << (tr * 8 + tf);
+            }
+        }
+
+        for (dir, &(df, dr)) in RAY_DELTAS.iter().enumerate() {
+            let mut mask = 0u64;
+            let (mut tf, mut tr) = (file + df, rank + dr);
+            while on_board(tf, tr) {
+                mask |= 1u64 << (tr * 8 + tf);
+                tf += df;
+                tr += dr;
+            }
+            ray_masks[dir][square as usize] = mask;
+        }
+    }
+
+    let mut out = String::new();
+    write_table(&mut out, "KNIGHT_ATTACKS", &knight_attacks);
+    write_table(&mut out, "KING_ATTACKS", &king_attacks);
+    write_table(&mut out, "PAWN_ATTACKS_WHITE", &pawn_attacks_white);
+    write_table(&mut out, "PAWN_ATTACKS_BLACK", &pawn_attacks_black);
+
+    writeln!(out, "pub const RAY_MASKS: [[u64; 64]; 8] = [").unwrap();
+    for dir in ray_masks.iter() {
+        write!(out, "    [").unwrap();
+        for value in dir.iter() {
+            write!(out, "{value}, ").unwrap();
+        }
+        writeln!(out, "],").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    fs::write(&dest_path, out).unwrap();
+    println!("cargo:rerun-if-changed=build.rs");
+}
+
+fn write_table(out: &mut String, name: &str, table: &[u64; 64]) {
+    write!(out, "pub const {name}: [u64; 64] = [").unwrap();
+    for value in table.iter() {
+        write!(out, "{value}, ").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
@@ -19,6 +19,26 @@ pub struct ChessMoveOutput {
     pub game_status: u8, // 0: ongoing, 1: checkmate, 2: draw
 }
 
+// Pieces are signed: positive is white, negative is black, magnitude is
+// pawn=1, knight=2, bishop=3, rook=4, queen=5, king=6.
+//
+// KNIGHT_ATTACKS, KING_ATTACKS, PAWN_ATTACKS_WHITE/BLACK and RAY_MASKS are
+// generated at build time by build.rs; see OUT_DIR/generated.rs. Every SP1
+// guest in this workspace shares this crate for move validation, attack
+// detection and Zobrist hashing rather than keeping its own copy, so the
+// rules only need to be correct in one place.
+include!(concat!(env!("OUT_DIR"), "/generated.rs"));
+
+// Ray mask direction indices: N, NE, E, SE, S, SW, W, NW.
+const DIR_N: usize = 0;
+const DIR_NE: usize = 1;
+const DIR_E: usize = 2;
+const DIR_SE: usize = 3;
+const DIR_S: usize = 4;
+const DIR_SW: usize = 5;
+const DIR_W: usize = 6;
+const DIR_NW: usize = 7;
+
 // This would be the main entry point for ZisK
 // #[zisk::main]
 pub fn validate_chess_move(input: ChessMoveInput) -> ChessMoveOutput {
@@ -28,15 +48,14 @@ pub fn validate_chess_move(input: ChessMoveInput) -> ChessMoveOutput {
     // 2. Validate move according to chess rules
     // 3. Check for check/checkmate/stalemate
     // 4. Return new board state if valid
-    
-    // Placeholder implementation
-    let is_valid = basic_move_validation(&input);
+
+    let is_valid = is_legal_move(&input.board_state, input.player_turn, input.move_from, input.move_to);
     let new_board_state = if is_valid {
-        apply_move(&input)
+        apply_move(&input.board_state, input.move_from as usize, input.move_to as usize)
     } else {
         input.board_state
     };
-    
+
     ChessMoveOutput {
         is_valid,
         new_board_state,
@@ -44,34 +63,603 @@ pub fn validate_chess_move(input: ChessMoveInput) -> ChessMoveOutput {
     }
 }
 
-fn basic_move_validation(input: &ChessMoveInput) -> bool {
+// Whether moving `board`'s piece on `from` to `to` is pseudo-legal for
+// `player_turn`: in bounds, the piece belongs to the mover, the
+// destination isn't a same-color piece, and the move matches the piece's
+// movement rules. This is the single shared move-legality check used by
+// every guest in the workspace.
+pub fn is_legal_move(board: &[i8; 64], player_turn: u8, from: u8, to: u8) -> bool {
+    is_legal_move_with_occupancy(board, player_turn, from, to, occupancy_bitboard(board))
+}
+
+// Same as `is_legal_move`, but for callers that already have `board`'s
+// occupancy bitboard computed (e.g. scanning every candidate move for one
+// side) so it isn't rebuilt by a fresh board scan on every call.
+fn is_legal_move_with_occupancy(board: &[i8; 64], player_turn: u8, from: u8, to: u8, occupancy: u64) -> bool {
+    basic_move_validation(from, to) && piece_move_is_legal(board, player_turn, from, to, occupancy)
+}
+
+fn basic_move_validation(from: u8, to: u8) -> bool {
     // Implement basic validation
     // Check if move is within bounds, piece exists, etc.
-    input.move_from < 64 && input.move_to < 64 && input.move_from != input.move_to
+    from < 64 && to < 64 && from != to
+}
+
+// Piece-specific pseudo-legal validation against `board`: checks the
+// moved piece belongs to `player_turn`, that the destination isn't occupied
+// by a same-color piece, and that the move matches the piece's movement
+// rules. Knight/king/pawn moves are table lookups; sliding pieces ray-walk
+// `occupancy` via `rook_attacks`/`bishop_attacks` rather than scanning the
+// board array.
+fn piece_move_is_legal(board: &[i8; 64], player_turn: u8, from: u8, to: u8, occupancy: u64) -> bool {
+    let (from, to) = (from as usize, to as usize);
+
+    let piece = board[from];
+    if piece == 0 {
+        return false;
+    }
+
+    let is_white = piece > 0;
+    if is_white != (player_turn == 0) {
+        return false;
+    }
+
+    let target = board[to];
+    if target != 0 && (target > 0) == is_white {
+        return false;
+    }
+
+    let to_bit = 1u64 << to;
+
+    match piece.abs() {
+        1 => pawn_move_is_legal(occupancy, from, to, is_white, target),
+        2 => KNIGHT_ATTACKS[from] & to_bit != 0,
+        3 => bishop_attacks(from, occupancy) & to_bit != 0,
+        4 => rook_attacks(from, occupancy) & to_bit != 0,
+        5 => (bishop_attacks(from, occupancy) | rook_attacks(from, occupancy)) & to_bit != 0,
+        6 => KING_ATTACKS[from] & to_bit != 0,
+        _ => false,
+    }
+}
+
+fn occupancy_bitboard(board: &[i8; 64]) -> u64 {
+    let mut occupancy = 0u64;
+    for (square, &piece) in board.iter().enumerate() {
+        if piece != 0 {
+            occupancy |= 1u64 << square;
+        }
+    }
+    occupancy
+}
+
+// Bitboard of the squares occupied by `is_white`'s pieces, so hot loops
+// that enumerate one side's moves can walk set bits via `trailing_zeros`
+// instead of scanning all 64 squares and rejecting the other 48 (or
+// more) that don't belong to that side.
+fn side_occupancy(board: &[i8; 64], is_white: bool) -> u64 {
+    let mut occupancy = 0u64;
+    for (square, &piece) in board.iter().enumerate() {
+        if piece != 0 && (piece > 0) == is_white {
+            occupancy |= 1u64 << square;
+        }
+    }
+    occupancy
+}
+
+fn pawn_move_is_legal(
+    occupancy: u64,
+    from: usize,
+    to: usize,
+    is_white: bool,
+    target: i8,
+) -> bool {
+    let to_bit = 1u64 << to;
+    let attacks = if is_white { PAWN_ATTACKS_WHITE[from] } else { PAWN_ATTACKS_BLACK[from] };
+    if attacks & to_bit != 0 {
+        // Diagonal moves are only legal when capturing.
+        return target != 0;
+    }
+
+    let (ff, fr) = (file_of(from), rank_of(from));
+    let (tf, tr) = (file_of(to), rank_of(to));
+    if tf != ff || target != 0 {
+        return false;
+    }
+
+    let direction = if is_white { 1 } else { -1 };
+    let start_rank = if is_white { 1 } else { 6 };
+
+    if tr - fr == direction {
+        return true;
+    }
+    if tr - fr == 2 * direction && fr == start_rank {
+        let mid = (from as i32 + 8 * direction) as usize;
+        return occupancy & (1u64 << mid) == 0;
+    }
+    false
+}
+
+// Attacks along a ray whose square index increases with distance (N, NE,
+// E, NW): the nearest blocker is the lowest set bit in the masked ray.
+fn positive_ray_attacks(dir: usize, from: usize, occupancy: u64) -> u64 {
+    let ray = RAY_MASKS[dir][from];
+    let blockers = ray & occupancy;
+    if blockers == 0 {
+        return ray;
+    }
+    let blocker_square = blockers.trailing_zeros() as usize;
+    ray ^ RAY_MASKS[dir][blocker_square]
+}
+
+// Attacks along a ray whose square index decreases with distance (S, SE,
+// SW, W): the nearest blocker is the highest set bit in the masked ray.
+fn negative_ray_attacks(dir: usize, from: usize, occupancy: u64) -> u64 {
+    let ray = RAY_MASKS[dir][from];
+    let blockers = ray & occupancy;
+    if blockers == 0 {
+        return ray;
+    }
+    let blocker_square = 63 - blockers.leading_zeros() as usize;
+    ray ^ RAY_MASKS[dir][blocker_square]
+}
+
+fn rook_attacks(from: usize, occupancy: u64) -> u64 {
+    positive_ray_attacks(DIR_N, from, occupancy)
+        | negative_ray_attacks(DIR_S, from, occupancy)
+        | positive_ray_attacks(DIR_E, from, occupancy)
+        | negative_ray_attacks(DIR_W, from, occupancy)
+}
+
+fn bishop_attacks(from: usize, occupancy: u64) -> u64 {
+    positive_ray_attacks(DIR_NE, from, occupancy)
+        | negative_ray_attacks(DIR_SE, from, occupancy)
+        | negative_ray_attacks(DIR_SW, from, occupancy)
+        | positive_ray_attacks(DIR_NW, from, occupancy)
 }
 
-fn apply_move(input: &ChessMoveInput) -> [i8; 64] {
-    let mut new_board = input.board_state;
-    let piece = new_board[input.move_from as usize];
-    new_board[input.move_from as usize] = 0;
-    new_board[input.move_to as usize] = piece;
+fn file_of(square: usize) -> i32 {
+    (square % 8) as i32
+}
+
+fn rank_of(square: usize) -> i32 {
+    (square / 8) as i32
+}
+
+pub fn apply_move(board: &[i8; 64], from: usize, to: usize) -> [i8; 64] {
+    let mut new_board = *board;
+    let piece = new_board[from];
+    new_board[from] = 0;
+    new_board[to] = piece;
     new_board
 }
 
+// Promotes a pawn that has just landed on `to` to `promotion`'s piece type
+// (2=knight, 3=bishop, 4=rook, 5=queen), signed for `player_turn`. Any
+// other piece, or a pawn that hasn't reached the back rank, is left
+// untouched. Shared by every guest that applies a move coming from an
+// untrusted wire format, so a promotion isn't silently dropped.
+pub fn apply_promotion(mut board: [i8; 64], to: usize, promotion: u8, player_turn: u8) -> [i8; 64] {
+    let is_white = player_turn == 0;
+    let back_rank = if is_white { 7 } else { 0 };
+
+    if board[to].abs() == 1 && to / 8 == back_rank && (2..=5).contains(&promotion) {
+        board[to] = if is_white { promotion as i8 } else { -(promotion as i8) };
+    }
+
+    board
+}
+
+// Whether any `attacker_is_white` piece on `board` attacks `target`, via
+// the same attack bitboards used for move validation. Pawn attacks don't
+// require `target` to be occupied, unlike pawn moves.
+pub fn square_attacked(board: &[i8; 64], target: usize, attacker_is_white: bool) -> bool {
+    let occupancy = occupancy_bitboard(board);
+
+    let pawn_attackers = if attacker_is_white { PAWN_ATTACKS_BLACK[target] } else { PAWN_ATTACKS_WHITE[target] };
+    let knight_attackers = KNIGHT_ATTACKS[target];
+    let king_attackers = KING_ATTACKS[target];
+    let diagonal_attackers = bishop_attacks(target, occupancy);
+    let orthogonal_attackers = rook_attacks(target, occupancy);
+
+    // `target` holds the defending king, so it's never one of
+    // `attacker_is_white`'s own squares - no need to special-case it out.
+    let mut attackers = side_occupancy(board, attacker_is_white);
+    while attackers != 0 {
+        let from = attackers.trailing_zeros() as usize;
+        attackers &= attackers - 1;
+        let from_bit = 1u64 << from;
+
+        let attacks = match board[from].abs() {
+            1 => pawn_attackers & from_bit != 0,
+            2 => knight_attackers & from_bit != 0,
+            3 => diagonal_attackers & from_bit != 0,
+            4 => orthogonal_attackers & from_bit != 0,
+            5 => (diagonal_attackers | orthogonal_attackers) & from_bit != 0,
+            6 => king_attackers & from_bit != 0,
+            _ => false,
+        };
+
+        if attacks {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn find_king_square(board: &[i8; 64], is_white: bool) -> Option<usize> {
+    let king_piece: i8 = if is_white { 6 } else { -6 };
+    board.iter().position(|&p| p == king_piece)
+}
+
+// Pseudo-legal -> legal filtering: a move is only legal if it doesn't
+// leave the mover's own king in check.
+pub fn king_is_safe_after_move(board_after: &[i8; 64], mover_turn: u8) -> bool {
+    let mover_is_white = mover_turn == 0;
+    match find_king_square(board_after, mover_is_white) {
+        Some(king_square) => !square_attacked(board_after, king_square, !mover_is_white),
+        None => false,
+    }
+}
+
+// Whether `turn` has any pseudo-legal move that doesn't leave its own
+// king in check. `occupancy` is computed once up front and `turn`'s own
+// pieces are walked bit by bit rather than rescanning all 64 squares (and
+// recomputing occupancy) for every one of the up to 64*64 candidate moves.
+pub fn has_any_legal_move(board: &[i8; 64], turn: u8) -> bool {
+    let is_white = turn == 0;
+    let occupancy = occupancy_bitboard(board);
+    let mut own_pieces = side_occupancy(board, is_white);
+
+    while own_pieces != 0 {
+        let from = own_pieces.trailing_zeros() as u8;
+        own_pieces &= own_pieces - 1;
+
+        for to in 0..64u8 {
+            if !is_legal_move_with_occupancy(board, turn, from, to, occupancy) {
+                continue;
+            }
+            let candidate_board = apply_move(board, from as usize, to as usize);
+            if king_is_safe_after_move(&candidate_board, turn) {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+// Terminal status of a game after the side to move is `turn`.
+pub const STATUS_ONGOING: u8 = 0;
+pub const STATUS_CHECKMATE: u8 = 1;
+pub const STATUS_STALEMATE: u8 = 2;
+pub const STATUS_DRAW: u8 = 3; // insufficient material; repetition/50-move are not tracked here
+
+// Whether the position is a dead draw on material alone: no pawns, rooks
+// or queens on the board, and at most one minor piece (bishop or knight)
+// total between both sides (K vs K, K+B vs K, K+N vs K). This is
+// deliberately conservative - two minors on the board (e.g. K+B vs K+B)
+// can in principle still be checkmated, so that case is left ongoing
+// rather than risk misreporting a drawn game status.
+fn is_insufficient_material(board: &[i8; 64]) -> bool {
+    let mut minor_pieces = 0u32;
+    for &piece in board.iter() {
+        match piece.abs() {
+            1 | 4 | 5 => return false, // pawn, rook or queen: material is sufficient
+            2 | 3 => minor_pieces += 1, // knight or bishop
+            _ => {}
+        }
+    }
+    minor_pieces <= 1
+}
+
+pub fn determine_game_status(board: &[i8; 64], turn: u8) -> u8 {
+    if is_insufficient_material(board) {
+        return STATUS_DRAW;
+    }
+
+    if has_any_legal_move(board, turn) {
+        return STATUS_ONGOING;
+    }
+
+    let is_white = turn == 0;
+    match find_king_square(board, is_white) {
+        Some(king_square) if square_attacked(board, king_square, !is_white) => STATUS_CHECKMATE,
+        Some(_) => STATUS_STALEMATE,
+        None => STATUS_ONGOING,
+    }
+}
+
+// A position is checkmate for `opponent_turn` when its king is attacked by
+// `attacker_is_white` and no pseudo-legal opponent reply escapes the
+// attack on the (possibly relocated) king. `occupancy` is computed once up
+// front (see `has_any_legal_move`) and `opponent_turn`'s pieces are walked
+// bit by bit rather than rescanning all 64 squares per candidate move.
+pub fn is_checkmate(board: &[i8; 64], opponent_turn: u8, attacker_is_white: bool) -> bool {
+    let opponent_is_white = opponent_turn == 0;
+    let king_square = match find_king_square(board, opponent_is_white) {
+        Some(square) => square,
+        None => return false,
+    };
+
+    if !square_attacked(board, king_square, attacker_is_white) {
+        return false;
+    }
+
+    let occupancy = occupancy_bitboard(board);
+    let mut opponent_pieces = side_occupancy(board, opponent_is_white);
+
+    while opponent_pieces != 0 {
+        let from = opponent_pieces.trailing_zeros() as usize;
+        opponent_pieces &= opponent_pieces - 1;
+        let piece = board[from];
+
+        for to in 0..64usize {
+            if !is_legal_move_with_occupancy(board, opponent_turn, from as u8, to as u8, occupancy) {
+                continue;
+            }
+
+            let escaped_board = apply_move(board, from, to);
+            let new_king_square = if piece.abs() == 6 { to } else { king_square };
+            if !square_attacked(&escaped_board, new_king_square, attacker_is_white) {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+// Zobrist keys for 12 piece types over 64 squares, plus side-to-move,
+// castling rights and en-passant file, generated deterministically from a
+// fixed seed so the prover and verifier always agree on the table.
+pub struct ZobristKeys {
+    pieces: [[u64; 64]; 12],
+    side: u64,
+    castling: [u64; 4],
+    en_passant: [u64; 8],
+}
+
+const ZOBRIST_SEED: u64 = 0x9E3779B97F4A7C15;
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E3779B97F4A7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+pub fn generate_zobrist_keys() -> ZobristKeys {
+    let mut state = ZOBRIST_SEED;
+
+    let mut pieces = [[0u64; 64]; 12];
+    for piece_table in pieces.iter_mut() {
+        for key in piece_table.iter_mut() {
+            *key = splitmix64(&mut state);
+        }
+    }
+
+    let side = splitmix64(&mut state);
+
+    let mut castling = [0u64; 4];
+    for key in castling.iter_mut() {
+        *key = splitmix64(&mut state);
+    }
+
+    let mut en_passant = [0u64; 8];
+    for key in en_passant.iter_mut() {
+        *key = splitmix64(&mut state);
+    }
+
+    ZobristKeys { pieces, side, castling, en_passant }
+}
+
+// Maps a signed piece (±1..=±6) to an index into `ZobristKeys::pieces`:
+// 0-5 are white pawn..king, 6-11 are black pawn..king.
+fn piece_index(piece: i8) -> usize {
+    if piece > 0 {
+        (piece - 1) as usize
+    } else {
+        (6 + (-piece) - 1) as usize
+    }
+}
+
+pub fn hash_position(
+    board: &[i8; 64],
+    player_turn: u8,
+    castling_rights: u8,
+    en_passant_file: u8,
+    keys: &ZobristKeys,
+) -> u64 {
+    let mut hash = 0u64;
+
+    for (square, &piece) in board.iter().enumerate() {
+        if piece != 0 {
+            hash ^= keys.pieces[piece_index(piece)][square];
+        }
+    }
+
+    if player_turn == 1 {
+        hash ^= keys.side;
+    }
+
+    for (i, key) in keys.castling.iter().enumerate() {
+        if castling_rights & (1 << i) != 0 {
+            hash ^= key;
+        }
+    }
+
+    if en_passant_file < 8 {
+        hash ^= keys.en_passant[en_passant_file as usize];
+    }
+
+    hash
+}
+
+// Incrementally updates a position hash for a single move: XOR out the
+// moved piece at `from`, XOR it back in at `to`, XOR out any captured
+// piece, and toggle the side-to-move key. Castling rights and en-passant
+// file are left untouched.
+pub fn hash_after_move(hash_before: u64, board: &[i8; 64], from: usize, to: usize, keys: &ZobristKeys) -> u64 {
+    let moving_piece = board[from];
+    let captured_piece = board[to];
+
+    let mut hash = hash_before;
+    hash ^= keys.pieces[piece_index(moving_piece)][from];
+    hash ^= keys.pieces[piece_index(moving_piece)][to];
+    if captured_piece != 0 {
+        hash ^= keys.pieces[piece_index(captured_piece)][to];
+    }
+    hash ^= keys.side;
+
+    hash
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn input_with_board(board: [i8; 64], from: u8, to: u8, player_turn: u8) -> ChessMoveInput {
+        ChessMoveInput {
+            board_state: board,
+            move_from: from,
+            move_to: to,
+            move_number: 1,
+            player_turn,
+        }
+    }
+
     #[test]
     fn test_basic_validation() {
-        let input = ChessMoveInput {
-            board_state: [0; 64],
-            move_from: 0,
-            move_to: 8,
-            move_number: 1,
-            player_turn: 0,
-        };
-        
-        assert!(basic_move_validation(&input));
+        assert!(basic_move_validation(0, 8));
+    }
+
+    #[test]
+    fn test_knight_move_valid() {
+        let mut board = [0; 64];
+        board[1] = 2; // white knight on b1
+        let input = input_with_board(board, 1, 18, 0); // b1 -> c3
+        assert!(validate_chess_move(input).is_valid);
+    }
+
+    #[test]
+    fn test_pawn_double_push_blocked() {
+        let mut board = [0; 64];
+        board[8] = 1; // white pawn on a2
+        board[16] = -1; // blocking piece on a3
+        let input = input_with_board(board, 8, 24, 0);
+        assert!(!validate_chess_move(input).is_valid);
+    }
+
+    #[test]
+    fn test_rook_blocked_by_piece() {
+        let mut board = [0; 64];
+        board[0] = 4; // white rook on a1
+        board[8] = 1; // white pawn on a2 blocks the file
+        let input = input_with_board(board, 0, 56, 0); // a1 -> a8
+        assert!(!validate_chess_move(input).is_valid);
+    }
+
+    #[test]
+    fn test_wrong_turn_rejected() {
+        let mut board = [0; 64];
+        board[1] = 2; // white knight on b1
+        let input = input_with_board(board, 1, 18, 1); // black to move
+        assert!(!validate_chess_move(input).is_valid);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_king_is_safe_after_move() {
+        let mut board = [0; 64];
+        board[4] = 6; // white king on e1
+        board[60] = -4; // black rook on e8
+
+        // King on the e-file facing the rook: in check.
+        assert!(!king_is_safe_after_move(&board, 0));
+
+        // King steps off the e-file: safe.
+        let moved = apply_move(&board, 4, 5); // Ke1-f1
+        assert!(king_is_safe_after_move(&moved, 0));
+    }
+
+    #[test]
+    fn test_is_checkmate_detects_back_rank_mate() {
+        // White king boxed in on h1 by its own pawns, black rook delivers
+        // mate along the back rank.
+        let mut board = [0; 64];
+        board[7] = 6; // white king on h1
+        board[14] = 1; // white pawn on g2
+        board[15] = 1; // white pawn on h2
+        board[0] = -4; // black rook on a1
+        assert!(is_checkmate(&board, 0, false));
+    }
+
+    #[test]
+    fn test_determine_game_status_draws_on_insufficient_material() {
+        // Bare kings.
+        let mut board = [0; 64];
+        board[4] = 6; // white king e1
+        board[60] = -6; // black king e8
+        assert_eq!(determine_game_status(&board, 0), STATUS_DRAW);
+
+        // King and lone bishop vs king is still insufficient material.
+        board[2] = 3; // white bishop c1
+        assert_eq!(determine_game_status(&board, 0), STATUS_DRAW);
+
+        // A second minor piece is enough that mate can't be ruled out.
+        board[5] = 3; // white bishop f1
+        assert_ne!(determine_game_status(&board, 0), STATUS_DRAW);
+    }
+
+    #[test]
+    fn test_determine_game_status_ongoing_with_rook() {
+        let mut board = [0; 64];
+        board[4] = 6; // white king e1
+        board[60] = -6; // black king e8
+        board[0] = 4; // white rook a1
+        assert_eq!(determine_game_status(&board, 0), STATUS_ONGOING);
+    }
+
+    // hash_after_move is an incremental update of hash_position - the two
+    // must agree on the resulting position's hash, or every committed
+    // hash_before/hash_after pair in three of the five guests is silently
+    // wrong. Castling rights and en-passant file are held fixed across
+    // the move since hash_after_move doesn't touch them.
+    #[test]
+    fn test_hash_after_move_matches_hash_position_for_non_capture() {
+        let keys = generate_zobrist_keys();
+        let mut board = [0i8; 64];
+        board[4] = 6; // white king e1
+        board[60] = -6; // black king e8
+        board[12] = 1; // white pawn e2
+        let castling_rights = 0b1111;
+        let en_passant_file = 8;
+
+        let hash_before = hash_position(&board, 0, castling_rights, en_passant_file, &keys);
+        let hash_after = hash_after_move(hash_before, &board, 12, 28, &keys); // e2-e4
+
+        let moved_board = apply_move(&board, 12, 28);
+        let expected = hash_position(&moved_board, 1, castling_rights, en_passant_file, &keys);
+        assert_eq!(hash_after, expected);
+    }
+
+    #[test]
+    fn test_hash_after_move_matches_hash_position_for_capture() {
+        let keys = generate_zobrist_keys();
+        let mut board = [0i8; 64];
+        board[4] = 6; // white king e1
+        board[60] = -6; // black king e8
+        board[0] = 4; // white rook a1
+        board[48] = -1; // black pawn a7
+        let castling_rights = 0b1111;
+        let en_passant_file = 8;
+
+        let hash_before = hash_position(&board, 0, castling_rights, en_passant_file, &keys);
+        let hash_after = hash_after_move(hash_before, &board, 0, 48, &keys); // Rxa7
+
+        let moved_board = apply_move(&board, 0, 48);
+        let expected = hash_position(&moved_board, 1, castling_rights, en_passant_file, &keys);
+        assert_eq!(hash_after, expected);
+    }
+}
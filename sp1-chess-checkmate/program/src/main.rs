@@ -0,0 +1,46 @@
+
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_zkvm::io::{read, commit};
+
+use chess_validator::{
+    apply_move, apply_promotion, generate_zobrist_keys, hash_position, is_checkmate,
+    is_legal_move, king_is_safe_after_move,
+};
+
+pub fn main() {
+    // Public: the starting position only.
+    let board: [i8; 64] = read();
+    let player_turn: u8 = read();
+    let castling_rights: u8 = read();
+    let en_passant_file: u8 = read();
+
+    // Private witness: the candidate mating move. Never committed, so the
+    // verifier only ever learns whether *some* forced mate exists.
+    let from: u8 = read();
+    let to: u8 = read();
+    let promotion: u8 = read();
+
+    let pseudo_legal = is_legal_move(&board, player_turn, from, to);
+    let new_board = if pseudo_legal {
+        apply_promotion(apply_move(&board, from as usize, to as usize), to as usize, promotion, player_turn)
+    } else {
+        board
+    };
+
+    // A move that leaves the mover's own king in check isn't legal chess,
+    // even if it's pseudo-legal per `is_legal_move` - without this the
+    // guest could "prove" a forced mate from a move that never escapes an
+    // existing check on the prover's own king.
+    let is_legal = pseudo_legal && king_is_safe_after_move(&new_board, player_turn);
+
+    let opponent_turn = 1 - player_turn;
+    let is_checkmate_move = is_legal && is_checkmate(&new_board, opponent_turn, player_turn == 0);
+
+    // Commit only a hash of the starting position plus the mate verdict.
+    let keys = generate_zobrist_keys();
+    let position_hash = hash_position(&board, player_turn, castling_rights, en_passant_file, &keys);
+    commit(&position_hash);
+    commit(&is_checkmate_move);
+}
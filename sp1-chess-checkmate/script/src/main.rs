@@ -0,0 +1,80 @@
+
+use sp1_sdk::{ProverClient, SP1Stdin, include_elf};
+use std::env;
+
+use chess_host_util::parse_fen;
+
+const ELF: &[u8] = include_elf!("chess-checkmate");
+
+// Fool's mate: after 1. f3 e5 2. g4, Black to move with Qd8-h4# available.
+// The position is public; the mating move is the private witness below.
+const DEFAULT_FEN: &str = "rnbqkbnr/pppp1ppp/8/4p3/6P1/5P2/PPPPP2P/RNBQKBNR b KQkq - 0 2";
+
+fn main() {
+    println!("🔐 Generating ZK checkmate proof...");
+
+    let fen = env::var("FEN").unwrap_or(DEFAULT_FEN.to_string());
+    let (board, player_turn, castling_rights, en_passant_file) = parse_fen(&fen);
+
+    // The candidate mating move: private witness, never committed.
+    let from_square: u8 = env::var("FROM_SQUARE")
+        .unwrap_or("59".to_string()) // d8
+        .parse()
+        .unwrap_or(59);
+
+    let to_square: u8 = env::var("TO_SQUARE")
+        .unwrap_or("31".to_string()) // h4
+        .parse()
+        .unwrap_or(31);
+
+    let promotion: u8 = env::var("PROMOTION")
+        .unwrap_or("0".to_string())
+        .parse()
+        .unwrap_or(0);
+
+    println!("📋 Proving a forced mate exists for the committed position (candidate move kept private)...");
+
+    let mut stdin = SP1Stdin::new();
+    stdin.write(&board);
+    stdin.write(&player_turn);
+    stdin.write(&castling_rights);
+    stdin.write(&en_passant_file);
+    stdin.write(&from_square);
+    stdin.write(&to_square);
+    stdin.write(&promotion);
+
+    let client = ProverClient::from_env();
+    println!("🔑 Setting up SP1 proving keys...");
+    let (pk, vk) = client.setup(ELF);
+
+    println!("⚡ Generating SP1 STARK proof...");
+    let start = std::time::Instant::now();
+
+    let proof = client.prove(&pk, &stdin)
+        .run()
+        .expect("SP1 proof generation failed");
+
+    let duration = start.elapsed();
+
+    println!("✅ Proof generated in {:.2}s", duration.as_secs_f64());
+    println!("📊 Proof size: {} bytes", proof.bytes().len());
+
+    println!("🔍 Verifying proof...");
+    client.verify(&proof, &vk)
+        .expect("SP1 proof verification failed");
+    println!("✅ Proof verified successfully!");
+
+    // Read public outputs: the starting position's hash and the verdict.
+    let position_hash = proof.public_values.read::<u64>();
+    let is_checkmate = proof.public_values.read::<bool>();
+
+    println!("🎯 Result:");
+    println!("   Position hash: {:016x}", position_hash);
+    println!("   Forced mate exists: {}", is_checkmate);
+
+    // Output for parsing by Node.js
+    println!("PROOF_SIZE:{}", proof.bytes().len());
+    println!("PROOF_TIME:{}", duration.as_millis());
+    println!("PROOF_VERIFIED:true");
+    println!("CHECKMATE_PROVEN:{}", is_checkmate);
+}
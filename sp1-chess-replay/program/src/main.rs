@@ -0,0 +1,55 @@
+
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_zkvm::io::{read, commit};
+
+use chess_validator::{
+    apply_move, apply_promotion, determine_game_status, generate_zobrist_keys, hash_position,
+    is_legal_move, king_is_safe_after_move,
+};
+
+pub fn main() {
+    let mut board: [i8; 64] = read();
+    let mut player_turn: u8 = read();
+    let _castling_rights: u8 = read();
+    let _en_passant_file: u8 = read();
+
+    let move_count: u32 = read();
+
+    let mut moves_applied: u32 = 0;
+    for _ in 0..move_count {
+        let from: u8 = read();
+        let to: u8 = read();
+        let promotion: u8 = read();
+
+        if !is_legal_move(&board, player_turn, from, to) {
+            break;
+        }
+
+        let candidate_board = apply_promotion(
+            apply_move(&board, from as usize, to as usize),
+            to as usize,
+            promotion,
+            player_turn,
+        );
+        if !king_is_safe_after_move(&candidate_board, player_turn) {
+            break;
+        }
+
+        board = candidate_board;
+        player_turn = 1 - player_turn;
+        moves_applied += 1;
+    }
+
+    let game_status = determine_game_status(&board, player_turn);
+
+    // Castling/en-passant rights aren't tracked across the replay, so the
+    // final hash binds piece placement and side to move only.
+    let keys = generate_zobrist_keys();
+    let final_hash = hash_position(&board, player_turn, 0, 8, &keys);
+
+    commit(&moves_applied);
+    commit(&final_hash);
+    commit(&game_status);
+}
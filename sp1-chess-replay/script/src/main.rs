@@ -0,0 +1,109 @@
+
+use sp1_sdk::{ProverClient, SP1Stdin, include_elf};
+use std::env;
+
+use chess_host_util::parse_fen;
+
+const ELF: &[u8] = include_elf!("chess-replay");
+
+const DEFAULT_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+// Scholar's mate: 1. e4 e5 2. Bc4 Nc6 3. Qh5 Nf6?? 4. Qxf7#
+// (from, to, promotion) - promotion is 0 (none) for every ply here since
+// no pawn reaches the back rank; see parse_moves for the wire format.
+const DEFAULT_MOVES: [(u8, u8, u8); 7] = [
+    (12, 28, 0), // e2-e4
+    (52, 36, 0), // e7-e5
+    (5, 26, 0),  // Bf1-c4
+    (57, 42, 0), // Nb8-c6
+    (3, 39, 0),  // Qd1-h5
+    (62, 45, 0), // Ng8-f6
+    (39, 53, 0), // Qh5xf7#
+];
+
+fn main() {
+    println!("🔐 Generating SP1 game-replay proof...");
+
+    let fen = env::var("FEN").unwrap_or(DEFAULT_FEN.to_string());
+    let (board, player_turn, castling_rights, en_passant_file) = parse_fen(&fen);
+
+    let moves = match env::var("MOVES") {
+        Ok(raw) => parse_moves(&raw),
+        Err(_) => DEFAULT_MOVES.to_vec(),
+    };
+
+    println!("📋 Replaying {} ply from the starting position...", moves.len());
+
+    let mut stdin = SP1Stdin::new();
+    stdin.write(&board);
+    stdin.write(&player_turn);
+    stdin.write(&castling_rights);
+    stdin.write(&en_passant_file);
+    stdin.write(&(moves.len() as u32));
+    for (from, to, promotion) in &moves {
+        stdin.write(from);
+        stdin.write(to);
+        stdin.write(promotion);
+    }
+
+    let client = ProverClient::from_env();
+    println!("🔑 Setting up SP1 proving keys...");
+    let (pk, vk) = client.setup(ELF);
+
+    println!("⚡ Generating SP1 STARK proof...");
+    let start = std::time::Instant::now();
+
+    let proof = client.prove(&pk, &stdin)
+        .run()
+        .expect("SP1 proof generation failed");
+
+    let duration = start.elapsed();
+
+    println!("✅ Proof generated in {:.2}s", duration.as_secs_f64());
+    println!("📊 Proof size: {} bytes", proof.bytes().len());
+
+    println!("🔍 Verifying proof...");
+    client.verify(&proof, &vk)
+        .expect("SP1 proof verification failed");
+    println!("✅ Proof verified successfully!");
+
+    let moves_applied = proof.public_values.read::<u32>();
+    let final_hash = proof.public_values.read::<u64>();
+    let game_status = proof.public_values.read::<u8>();
+
+    println!("🎯 Replay result:");
+    println!("   Moves applied: {}/{}", moves_applied, moves.len());
+    println!("   Final position hash: {:016x}", final_hash);
+    println!("   Game status: {}", game_status_name(game_status));
+
+    // Output for parsing by Node.js
+    println!("PROOF_SIZE:{}", proof.bytes().len());
+    println!("PROOF_TIME:{}", duration.as_millis());
+    println!("PROOF_VERIFIED:true");
+    println!("MOVES_APPLIED:{}", moves_applied);
+    println!("GAME_STATUS:{}", game_status);
+}
+
+fn game_status_name(status: u8) -> &'static str {
+    match status {
+        0 => "ongoing",
+        1 => "checkmate",
+        2 => "stalemate",
+        3 => "draw",
+        _ => "unknown",
+    }
+}
+
+// Each move is "from:to" or "from:to:promotion" (2=knight, 3=bishop,
+// 4=rook, 5=queen); a missing promotion field means "not a promotion".
+fn parse_moves(raw: &str) -> Vec<(u8, u8, u8)> {
+    raw.split(',')
+        .filter_map(|mv| {
+            let mut squares = mv.split(':');
+            let from: u8 = squares.next()?.trim().parse().ok()?;
+            let to: u8 = squares.next()?.trim().parse().ok()?;
+            let promotion: u8 = squares.next().and_then(|p| p.trim().parse().ok()).unwrap_or(0);
+            Some((from, to, promotion))
+        })
+        .collect()
+}
@@ -4,29 +4,36 @@ sp1_zkvm::entrypoint!(main);
 
 use sp1_zkvm::io::{read, commit};
 
+use chess_validator::{generate_zobrist_keys, hash_after_move, hash_position, is_legal_move};
+
 pub fn main() {
     // Read chess move data
+    let board_state: [i8; 64] = read();
+    let player_turn: u8 = read();
+    let castling_rights: u8 = read();
+    let en_passant_file: u8 = read();
     let from_square: u8 = read();
     let to_square: u8 = read();
     let move_number: u32 = read();
-    
-    // Basic chess validation
-    let is_valid_move = validate_chess_move(from_square, to_square);
-    
+
+    // Piece-aware chess validation
+    let is_valid_move = is_legal_move(&board_state, player_turn, from_square, to_square);
+
+    // Zobrist hash of the position before and after the move, binding the
+    // proof to both without revealing the full board.
+    let keys = generate_zobrist_keys();
+    let hash_before = hash_position(&board_state, player_turn, castling_rights, en_passant_file, &keys);
+    let hash_after = if is_valid_move {
+        hash_after_move(hash_before, &board_state, from_square as usize, to_square as usize, &keys)
+    } else {
+        hash_before
+    };
+
     // Commit results
     commit(&is_valid_move);
     commit(&from_square);
     commit(&to_square);
     commit(&move_number);
-}
-
-fn validate_chess_move(from: u8, to: u8) -> bool {
-    // Basic validation: squares must be different and within board bounds
-    if from >= 64 || to >= 64 || from == to {
-        return false;
-    }
-    
-    // Basic chess rules - pieces can move anywhere for now
-    // In a full implementation, this would validate piece-specific moves
-    true
+    commit(&hash_before);
+    commit(&hash_after);
 }
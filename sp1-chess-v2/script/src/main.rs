@@ -2,61 +2,74 @@
 use sp1_sdk::{ProverClient, SP1Stdin, include_elf};
 use std::env;
 
+use chess_host_util::parse_fen;
+
 const ELF: &[u8] = include_elf!("chess-v2");
 
+// Defaults to the starting position after 1. e4, so the default FROM_SQUARE
+// / TO_SQUARE (e7 -> e5) is a legal reply for the side to move.
+const DEFAULT_FEN: &str = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1";
+
 fn main() {
     println!("🔐 Generating real SP1 v2.0.0 STARK proof...");
-    
+
     // Get chess move from environment
     let from_square: u8 = env::var("FROM_SQUARE")
         .unwrap_or("52".to_string())
         .parse()
         .unwrap_or(52);
-    
+
     let to_square: u8 = env::var("TO_SQUARE")
         .unwrap_or("36".to_string())
         .parse()
         .unwrap_or(36);
-        
+
     let move_number: u32 = env::var("MOVE_NUMBER")
         .unwrap_or("1".to_string())
         .parse()
         .unwrap_or(1);
-    
-    println!("📋 Validating chess move: {} -> {} (move #{})", from_square, to_square, move_number);
-    
+
+    let fen = env::var("FEN").unwrap_or(DEFAULT_FEN.to_string());
+    let (board, player_turn, castling_rights, en_passant_file) = parse_fen(&fen);
+
+    println!("📋 Validating chess move: {} -> {} (move #{}, player {})", from_square, to_square, move_number, player_turn);
+
     // Prepare input for SP1 program
     let mut stdin = SP1Stdin::new();
+    stdin.write(&board);
+    stdin.write(&player_turn);
+    stdin.write(&castling_rights);
+    stdin.write(&en_passant_file);
     stdin.write(&from_square);
     stdin.write(&to_square);
     stdin.write(&move_number);
-    
+
     // Initialize SP1 client
     let client = ProverClient::from_env();
     println!("🔑 Setting up SP1 proving keys...");
     let (pk, vk) = client.setup(ELF);
-    
+
     // Generate STARK proof
     println!("⚡ Generating SP1 STARK proof...");
     let start = std::time::Instant::now();
-    
+
     let proof = client.prove(&pk, &stdin)
         .run()
         .expect("SP1 proof generation failed");
-    
+
     let duration = start.elapsed();
-    
+
     println!("✅ Real SP1 STARK proof generated!");
     println!("⏱️  Proof time: {:.2}s", duration.as_secs_f64());
     println!("📊 Proof size: {} bytes", proof.bytes().len());
-    
+
     // Verify the proof
     println!("🔍 Verifying SP1 proof...");
     client.verify(&proof, &vk)
         .expect("SP1 proof verification failed");
-    
+
     println!("✅ Proof verified successfully!");
-    
+
     // Output for parsing by Node.js
     println!("PROOF_SIZE:{}", proof.bytes().len());
     println!("PROOF_TIME:{}", duration.as_millis());
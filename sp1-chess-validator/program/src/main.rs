@@ -4,11 +4,27 @@ sp1_zkvm::entrypoint!(main);
 
 use sp1_zkvm::io::{read, commit};
 
+use chess_validator::{generate_zobrist_keys, hash_after_move, hash_position, is_legal_move};
+
 pub fn main() {
+    let board: [i8; 64] = read();
+    let player_turn: u8 = read();
+    let castling_rights: u8 = read();
+    let en_passant_file: u8 = read();
     let from: u8 = read();
     let to: u8 = read();
-    
-    let is_valid = from != to && from < 64 && to < 64;
-    
+
+    let is_valid = is_legal_move(&board, player_turn, from, to);
+
+    let keys = generate_zobrist_keys();
+    let hash_before = hash_position(&board, player_turn, castling_rights, en_passant_file, &keys);
+    let hash_after = if is_valid {
+        hash_after_move(hash_before, &board, from as usize, to as usize, &keys)
+    } else {
+        hash_before
+    };
+
     commit(&is_valid);
+    commit(&hash_before);
+    commit(&hash_after);
 }
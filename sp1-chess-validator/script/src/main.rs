@@ -1,23 +1,37 @@
 
 use sp1_sdk::{ProverClient, SP1Stdin, include_elf};
+use std::env;
+
+use chess_host_util::parse_fen;
 
 const ELF: &[u8] = include_elf!("minimal-chess");
 
+// Defaults to the starting position after 1. e4, so the hardcoded move
+// below (e7 -> e5) is a legal reply for the side to move.
+const DEFAULT_FEN: &str = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1";
+
 fn main() {
     println!("Generating minimal chess proof...");
-    
+
+    let fen = env::var("FEN").unwrap_or(DEFAULT_FEN.to_string());
+    let (board, player_turn, castling_rights, en_passant_file) = parse_fen(&fen);
+
     let mut stdin = SP1Stdin::new();
+    stdin.write(&board);
+    stdin.write(&player_turn);
+    stdin.write(&castling_rights);
+    stdin.write(&en_passant_file);
     stdin.write(&52u8);
     stdin.write(&36u8);
-    
+
     let client = ProverClient::from_env();
     let (pk, vk) = client.setup(ELF);
-    
+
     let proof = client.prove(&pk, &stdin).run().expect("Proving failed");
-    
+
     println!("Proof generated successfully!");
     println!("Proof size: {} bytes", proof.bytes().len());
-    
+
     client.verify(&proof, &vk).expect("Verification failed");
     println!("Proof verified!");
 }
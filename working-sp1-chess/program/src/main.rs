@@ -4,26 +4,38 @@ sp1_zkvm::entrypoint!(main);
 
 use sp1_zkvm::io::{read, commit};
 
+use chess_validator::{generate_zobrist_keys, hash_after_move, hash_position, is_legal_move};
+
 pub fn main() {
     // Read chess move data
+    let board_state: [i8; 64] = read();
+    let player_turn: u8 = read();
+    let castling_rights: u8 = read();
+    let en_passant_file: u8 = read();
     let from_square: u8 = read();
     let to_square: u8 = read();
     let move_number: u32 = read();
-    
-    // Basic chess move validation
-    let is_valid_squares = from_square < 64 && to_square < 64;
-    let is_different_squares = from_square != to_square;
+
+    // Piece-aware chess move validation
     let is_valid_move_number = move_number > 0;
-    
-    let is_valid = is_valid_squares && is_different_squares && is_valid_move_number;
-    
+    let is_valid = is_valid_move_number
+        && is_legal_move(&board_state, player_turn, from_square, to_square);
+
+    // Zobrist hash of the position before and after the move, binding the
+    // proof to both positions instead of a weak additive checksum.
+    let keys = generate_zobrist_keys();
+    let hash_before = hash_position(&board_state, player_turn, castling_rights, en_passant_file, &keys);
+    let hash_after = if is_valid {
+        hash_after_move(hash_before, &board_state, from_square as usize, to_square as usize, &keys)
+    } else {
+        hash_before
+    };
+
     // Commit results
     commit(&is_valid);
     commit(&from_square);
     commit(&to_square);
     commit(&move_number);
-    
-    // Simple checksum for verification
-    let checksum = from_square as u32 + to_square as u32 + move_number;
-    commit(&checksum);
+    commit(&hash_before);
+    commit(&hash_after);
 }
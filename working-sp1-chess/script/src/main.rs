@@ -2,31 +2,44 @@
 use sp1_sdk::{ProverClient, SP1Stdin, include_elf};
 use std::env;
 
+use chess_host_util::parse_fen;
+
 const ELF: &[u8] = include_elf!("stable-chess");
 
+// Defaults to the starting position after 1. e4, so the default FROM_SQUARE
+// / TO_SQUARE (e7 -> e5) is a legal reply for the side to move.
+const DEFAULT_FEN: &str = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1";
+
 fn main() {
     println!("🔐 Generating real SP1 STARK proof for chess move...");
-    
+
     // Read input from environment variables
     let from_square: u8 = env::var("FROM_SQUARE")
         .unwrap_or("52".to_string())
         .parse()
         .unwrap_or(52);
-    
+
     let to_square: u8 = env::var("TO_SQUARE")
         .unwrap_or("36".to_string())
         .parse()
         .unwrap_or(36);
-        
+
     let move_number: u32 = env::var("MOVE_NUMBER")
         .unwrap_or("1".to_string())
         .parse()
         .unwrap_or(1);
-    
+
+    let fen = env::var("FEN").unwrap_or(DEFAULT_FEN.to_string());
+    let (board, player_turn, castling_rights, en_passant_file) = parse_fen(&fen);
+
     println!("📋 Chess move: {} → {} (move #{})", from_square, to_square, move_number);
-    
+
     // Setup inputs
     let mut stdin = SP1Stdin::new();
+    stdin.write(&board);
+    stdin.write(&player_turn);
+    stdin.write(&castling_rights);
+    stdin.write(&en_passant_file);
     stdin.write(&from_square);
     stdin.write(&to_square);
     stdin.write(&move_number);
@@ -67,15 +80,17 @@ fn main() {
     let from_verified = proof.public_values.read::<u8>();
     let to_verified = proof.public_values.read::<u8>();
     let move_num_verified = proof.public_values.read::<u32>();
-    let checksum = proof.public_values.read::<u32>();
-    
+    let zobrist_before = proof.public_values.read::<u64>();
+    let zobrist_after = proof.public_values.read::<u64>();
+
     println!("🎯 Chess move validation results:");
     println!("   Valid move: {}", is_valid);
     println!("   From square: {}", from_verified);
     println!("   To square: {}", to_verified);
     println!("   Move number: {}", move_num_verified);
-    println!("   Checksum: {}", checksum);
-    
+    println!("   Zobrist hash before: {:016x}", zobrist_before);
+    println!("   Zobrist hash after:  {:016x}", zobrist_after);
+
     // Output for parsing by Node.js
     println!("PROOF_RESULT:SUCCESS");
     println!("PROOF_SIZE:{}", proof.bytes().len());